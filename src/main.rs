@@ -1,4 +1,5 @@
 use std::sync::Arc;
+use serde::Deserialize;
 use serde_json::Value;
 use serde_json::json;
 use ton_client::ClientConfig;
@@ -10,26 +11,53 @@ use ton_client::abi::ParamsOfEncodeMessage;
 use ton_client::abi::CallSet;
 use ton_client::abi::Signer;
 use ton_client::crypto::KeyPair;
+use ton_client::net::ParamsOfQueryCollection;
 use ton_client::processing::ParamsOfProcessMessage;
 use ton_client::processing::ProcessingEvent;
 
+mod manifest;
+mod rpc;
+
 const NETWORK_URL: &str = "net.ton.dev";
-const WORKCHAIN: i32 = 0;
+pub(crate) const WORKCHAIN: i32 = 0;
 const CONFIG_PATH: &str = "config.json";
+const DEFAULT_HD_PATH: &str = "m/44'/396'/0'/0/0";
 
 #[tokio::main]
 async fn main() {
+    if std::env::args().any(|a| a == "--serve") {
+        return match rpc::serve().await {
+            Ok(_) => (),
+            Err(e) => println!("RPC server failed: {}", e),
+        };
+    }
+
     let config = match get_config() {
         Ok(v) => v,
         Err(e) => return println!("Cannot load config: {}", e),
     };
+
+    if let Some(manifest_path) = config["manifest"].as_str() {
+        let network: NetworkSpec = serde_json::from_value(config["network"].clone()).unwrap_or_default();
+        return match manifest::deploy_manifest(manifest_path, network).await {
+            Ok(_) => println!("Ok"),
+            Err(e) => println!("Fail: {}", e),
+        };
+    }
+
     let initial_data = match get_initial_data(config["initial_data"].as_str()) {
         Ok(v) => v,
         Err(e) => return println!("Cannot load initial data: {}", e),
     };
+    let calls: Vec<CallSpec> = serde_json::from_value(config["calls"].clone()).unwrap_or_default();
+    let giver: Option<GiverSpec> = serde_json::from_value(config["giver"].clone()).ok();
+    let network: NetworkSpec = serde_json::from_value(config["network"].clone()).unwrap_or_default();
     match deploy(
         config["parameters"].as_str(),
         initial_data,
+        calls,
+        giver,
+        network,
     ).await {
         Ok(_) => println!("Ok"),
         Err(e) => println!("Fail: {}", e),
@@ -50,16 +78,40 @@ fn get_config() -> Result<Value, String> {
 async fn deploy(
     params: Option<&str>,
     initial_data: Value,
+    calls: Vec<CallSpec>,
+    giver: Option<GiverSpec>,
+    network: NetworkSpec,
 ) -> Result<(), String> {
-    let abi = Some(load_abi(initial_data["abi_path"].as_str())?);
+    let abi_path = load_abi(initial_data["abi_path"].as_str())?;
+    let abi = read_abi_file(&abi_path)?;
     let params = Some(load_params(params.unwrap())?);
-    deploy_contract(
+    let wait_secs = initial_data["wait_secs"].as_u64();
+    let keys: KeySource = serde_json::from_value(initial_data.clone()).unwrap_or_default();
+    let outcome = deploy_contract(
         initial_data["code_base64"].as_str().unwrap(),
-        &abi.unwrap(),
+        abi,
         &params.unwrap(),
-        initial_data["public_key"].as_str(),
-        initial_data["secret_key"].as_str(),
-    ).await
+        DeployOptions {
+            keys,
+            wait_secs,
+            calls,
+            giver,
+            network,
+        },
+        print_processing_event,
+    ).await?;
+
+    println!("Transaction succeeded.");
+    println!("Contract deployed at address: {}", outcome.address);
+    for (function, output) in &outcome.call_outputs {
+        println!("Call {} returned: {}", function, output);
+    }
+    if let Some(account) = outcome.account {
+        println!("Account is Active.");
+        println!("Balance: {}", account["balance"].as_str().unwrap_or("unknown"));
+        println!("Code hash: {}", account["code_hash"].as_str().unwrap_or("unknown"));
+    }
+    Ok(())
 }
 
 fn load_abi(abi_path: Option<&str>) -> Result<String, String> {
@@ -67,6 +119,19 @@ fn load_abi(abi_path: Option<&str>) -> Result<String, String> {
        .ok_or("ABI file is not defined. Supply it in the config.json.".to_string())
 }
 
+pub(crate) fn parse_abi(abi_str: &str) -> Result<Abi, String> {
+    Ok(Abi::Contract(
+        serde_json::from_str::<AbiContract>(abi_str)
+            .map_err(|e| format!("ABI is not a valid json: {}", e))?,
+    ))
+}
+
+fn read_abi_file(path: &str) -> Result<Abi, String> {
+    let abi_str = std::fs::read_to_string(path)
+        .map_err(|e| format!("failed to read ABI file: {}", e))?;
+    parse_abi(&abi_str)
+}
+
 fn load_params(params: &str) -> Result<String, String> {
     Ok(if params.find('{').is_none() {
         std::fs::read_to_string(params)
@@ -76,74 +141,241 @@ fn load_params(params: &str) -> Result<String, String> {
     })
 }
 
-fn create_client_verbose() -> Result<Arc<ClientContext>, String> {
+// Network presets, an explicit endpoints list, retry/timeout tuning, and the
+// workchain to deploy into — all overridable via the `network` config key.
+#[derive(Deserialize, Clone, Default)]
+pub(crate) struct NetworkSpec {
+    pub preset: Option<String>,
+    pub endpoints: Option<Vec<String>>,
+    pub message_processing_timeout: Option<u32>,
+    pub wait_for_timeout: Option<u32>,
+    pub network_retries_count: Option<i8>,
+    pub workchain_id: Option<i32>,
+}
+
+fn preset_endpoints(name: &str) -> Result<Vec<String>, String> {
+    match name {
+        "mainnet" => Ok(vec![
+            "main.ton.dev".to_string(),
+            "main2.ton.dev".to_string(),
+            "main3.ton.dev".to_string(),
+            "main4.ton.dev".to_string(),
+        ]),
+        "devnet" => Ok(vec![
+            "net.ton.dev".to_string(),
+            "net1.ton.dev".to_string(),
+            "net5.ton.dev".to_string(),
+        ]),
+        "local" => Ok(vec!["http://localhost".to_string()]),
+        other => Err(format!("unknown network preset: {}", other)),
+    }
+}
+
+fn resolve_endpoints(network: &NetworkSpec) -> Result<Vec<String>, String> {
+    if let Some(endpoints) = &network.endpoints {
+        return Ok(endpoints.clone());
+    }
+    if let Some(preset) = &network.preset {
+        return preset_endpoints(preset);
+    }
+    Ok(vec![NETWORK_URL.to_string()])
+}
+
+fn create_client_verbose(network: &NetworkSpec) -> Result<Arc<ClientContext>, String> {
+    let endpoints = resolve_endpoints(network)?;
+    let mut net_config = ton_client::net::NetworkConfig {
+        endpoints: Some(endpoints),
+        message_processing_timeout: network.message_processing_timeout.unwrap_or(30000),
+        ..Default::default()
+    };
+    if let Some(timeout) = network.wait_for_timeout {
+        net_config.wait_for_timeout = timeout;
+    }
+    if let Some(retries) = network.network_retries_count {
+        net_config.network_retries_count = retries;
+    }
     Ok(Arc::new(ClientContext::new(ClientConfig {
-        network: ton_client::net::NetworkConfig {
-            server_address: Some(NETWORK_URL.to_owned()),
-            message_processing_timeout: 30000,
-            ..Default::default()
-        },
+        network: net_config,
         ..Default::default()
     }).map_err(|e| format!("failed to create tonclient: {}", e))?))
-    // create_client(workchain_id, is_json, endpoints)
+}
+
+pub(crate) fn print_processing_event(event: ProcessingEvent) {
+    if let ProcessingEvent::DidSend { shard_block_id: _, message_id, message: _ } = event {
+        println!("MessageId: {}", message_id)
+    }
 }
 
 async fn process_message(
     ton: Arc<ClientContext>,
     msg: ParamsOfEncodeMessage,
-    is_json: bool,
+    on_event: impl Fn(ProcessingEvent) + Send + Sync + 'static,
 ) -> Result<serde_json::Value, String> {
-    let callback = |event| { async move {
-        if let ProcessingEvent::DidSend { shard_block_id: _, message_id, message: _ } = event {
-            println!("MessageId: {}", message_id)
-        }
-    }};
-    let res = if !is_json {
-        ton_client::processing::process_message(
-            ton.clone(),
-            ParamsOfProcessMessage {
-                message_encode_params: msg.clone(),
-                send_events: true,
-                ..Default::default()
-            },
-            callback,
-        ).await
-    } else {
-        ton_client::processing::process_message(
-            ton.clone(),
-            ParamsOfProcessMessage {
-                message_encode_params: msg.clone(),
-                send_events: true,
-                ..Default::default()
-            },
-            |_| { async move {} },
-        ).await
-    }.map_err(|e| format!("{:#}", e))?;
+    let res = ton_client::processing::process_message(
+        ton.clone(),
+        ParamsOfProcessMessage {
+            message_encode_params: msg.clone(),
+            send_events: true,
+            ..Default::default()
+        },
+        move |event| {
+            on_event(event);
+            async move {}
+        },
+    ).await.map_err(|e| format!("{:#}", e))?;
 
     Ok(res.decoded.and_then(|d| d.output).unwrap_or(json!({})))
 }
 
-async fn deploy_contract(
+pub(crate) struct DeployOutcome {
+    pub address: String,
+    pub account: Option<Value>,
+    pub call_outputs: std::collections::HashMap<String, Value>,
+}
+
+// Everything about a deploy beyond the contract image/ABI/params themselves:
+// signer, post-deploy wait, follow-up calls, funding, and network tuning.
+#[derive(Clone, Default)]
+pub(crate) struct DeployOptions {
+    pub keys: KeySource,
+    pub wait_secs: Option<u64>,
+    pub calls: Vec<CallSpec>,
+    pub giver: Option<GiverSpec>,
+    pub network: NetworkSpec,
+}
+
+pub(crate) async fn deploy_contract(
     code_base64: &str,
-    abi: &str,
+    abi: Abi,
     params: &str,
-    public_key: Option<&str>,
-    secret_key: Option<&str>,
-) -> Result<(), String> {
-    let ton = create_client_verbose()?;
+    options: DeployOptions,
+    on_event: impl Fn(ProcessingEvent) + Send + Sync + 'static,
+) -> Result<DeployOutcome, String> {
+    let workchain_id = options.network.workchain_id.unwrap_or(WORKCHAIN);
+    let ton = create_client_verbose(&options.network)?;
+    let keypair = resolve_keypair(&options.keys).await?;
     let (msg, addr) = prepare_deploy_message(
         code_base64,
-        abi,
+        abi.clone(),
         params,
-        public_key,
-        secret_key,
+        &keypair,
+        workchain_id,
     ).await?;
 
-    process_message(ton.clone(), msg, false).await?;
+    if let Some(giver) = &options.giver {
+        fund_from_giver(ton.clone(), giver, &addr).await?;
+    }
 
-    println!("Transaction succeeded.");
-    println!("Contract deployed at address: {}", addr);
-    Ok(())
+    let baseline_lt = query_account(ton.clone(), &addr).await.ok()
+        .and_then(|a| a["last_trans_lt"].as_str().map(|s| s.to_string()));
+
+    process_message(ton.clone(), msg, on_event).await?;
+
+    let call_outputs = run_post_deploy_calls(ton.clone(), &abi, &addr, &keypair, &options.calls).await?;
+
+    let account = match options.wait_secs {
+        Some(secs) => Some(wait_for_active(ton.clone(), &addr, baseline_lt, secs).await?),
+        None => None,
+    };
+
+    Ok(DeployOutcome { address: addr, account, call_outputs })
+}
+
+async fn query_account(ton: Arc<ClientContext>, addr: &str) -> Result<Value, String> {
+    let result = ton_client::net::query_collection(
+        ton,
+        ParamsOfQueryCollection {
+            collection: "accounts".to_string(),
+            filter: Some(json!({ "id": { "eq": addr } })),
+            result: "acc_type last_trans_lt balance code_hash".to_string(),
+            limit: Some(1),
+            order: None,
+        },
+    ).await.map_err(|e| format!("failed to query account {}: {}", addr, e))?;
+    Ok(result.result.into_iter().next().unwrap_or(json!({})))
+}
+
+// Polls the `accounts` collection until `acc_type` reports Active (1) with a
+// `last_trans_lt` different from the pre-deploy baseline, or times out.
+async fn wait_for_active(
+    ton: Arc<ClientContext>,
+    addr: &str,
+    baseline_lt: Option<String>,
+    timeout_secs: u64,
+) -> Result<Value, String> {
+    let started = std::time::Instant::now();
+    loop {
+        let account = query_account(ton.clone(), addr).await?;
+        let acc_type = account["acc_type"].as_i64();
+        let last_trans_lt = account["last_trans_lt"].as_str().map(|s| s.to_string());
+        if acc_type == Some(1) && last_trans_lt != baseline_lt {
+            return Ok(account);
+        }
+        if started.elapsed().as_secs() >= timeout_secs {
+            return Err(format!("timed out after {}s waiting for account {} to become Active", timeout_secs, addr));
+        }
+        tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+    }
+}
+
+const FUND_TIMEOUT_SECS: u64 = 60;
+
+// Funds a freshly computed deploy address from an already-active giver
+// contract before the deploy message is sent, so the account has balance.
+#[derive(Deserialize, Clone)]
+pub(crate) struct GiverSpec {
+    pub address: String,
+    pub abi_path: String,
+    pub amount: u64,
+    #[serde(default = "default_giver_function")]
+    pub function: String,
+    // Overrides the default `{dest, value, bounce}` shape, required when
+    // `function` isn't `sendTransaction`/`sendGrams` and expects other params.
+    #[serde(default)]
+    pub input: Option<Value>,
+    #[serde(flatten)]
+    pub keys: KeySource,
+}
+
+fn default_giver_function() -> String {
+    "sendTransaction".to_string()
+}
+
+async fn fund_from_giver(
+    ton: Arc<ClientContext>,
+    giver: &GiverSpec,
+    target_addr: &str,
+) -> Result<(), String> {
+    let abi = read_abi_file(&giver.abi_path)?;
+    let keypair = resolve_keypair(&giver.keys).await?;
+    let input = giver.input.clone()
+        .unwrap_or_else(|| json!({ "dest": target_addr, "value": giver.amount, "bounce": false }));
+    let msg = ParamsOfEncodeMessage {
+        abi,
+        address: Some(giver.address.clone()),
+        call_set: CallSet::some_with_function_and_input(&giver.function, input),
+        signer: keypair_signer(&keypair),
+        ..Default::default()
+    };
+    process_message(ton.clone(), msg, print_processing_event).await?;
+    wait_for_balance(ton, target_addr, giver.amount).await
+}
+
+async fn wait_for_balance(ton: Arc<ClientContext>, addr: &str, min_amount: u64) -> Result<(), String> {
+    let started = std::time::Instant::now();
+    loop {
+        let account = query_account(ton.clone(), addr).await?;
+        let balance = account["balance"].as_str()
+            .and_then(|b| u128::from_str_radix(b.trim_start_matches("0x"), 16).ok())
+            .unwrap_or(0);
+        if balance >= min_amount as u128 {
+            return Ok(());
+        }
+        if started.elapsed().as_secs() >= FUND_TIMEOUT_SECS {
+            return Err(format!("timed out after {}s waiting for {} to receive funds from giver", FUND_TIMEOUT_SECS, addr));
+        }
+        tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+    }
 }
 
 fn get_context() -> Result<Arc<ClientContext>, String> {
@@ -151,15 +383,16 @@ fn get_context() -> Result<Arc<ClientContext>, String> {
         .map_err(|e| format!("failed to create client context: {}", e))?))
 }
 
-async fn calc_acc_address(
+pub(crate) async fn calc_acc_address(
     tvc_base64: String,
     pubkey: Option<String>,
     abi: Abi,
+    workchain_id: i32,
 ) -> Result<String, String> {
     let ton = get_context();
     let dset = DeploySet {
         tvc: tvc_base64,
-        workchain_id: Some(WORKCHAIN),
+        workchain_id: Some(workchain_id),
         ..Default::default()
     };
     let result = ton_client::abi::encode_message(
@@ -182,8 +415,33 @@ async fn calc_acc_address(
     Ok(result.address)
 }
 
-fn load_keypair(public_key: Option<&str>, secret_key: Option<&str>) -> Result<Option<KeyPair>, String> {
-    if let (Some(p), Some(s)) = (public_key, secret_key) {
+// Raw keys, a BIP39 seed phrase, or nothing (Signer::None) — mutually exclusive.
+#[derive(Deserialize, Default, Clone)]
+pub(crate) struct KeySource {
+    pub public_key: Option<String>,
+    pub secret_key: Option<String>,
+    pub seed_phrase: Option<String>,
+    pub hd_path: Option<String>,
+}
+
+pub(crate) async fn resolve_keypair(keys: &KeySource) -> Result<Option<KeyPair>, String> {
+    let has_raw_public = keys.public_key.is_some();
+    let has_raw_secret = keys.secret_key.is_some();
+    let has_seed = keys.seed_phrase.is_some();
+
+    if has_seed && (has_raw_public || has_raw_secret) {
+        return Err("supply either a seed_phrase or a raw public_key/secret_key pair, not both".to_string());
+    }
+    if has_raw_public != has_raw_secret {
+        return Err("both public_key and secret_key must be supplied together".to_string());
+    }
+
+    if let Some(phrase) = &keys.seed_phrase {
+        let ton = get_context()?;
+        return Ok(Some(derive_keypair_from_seed(ton, phrase, keys.hd_path.as_deref()).await?));
+    }
+
+    if let (Some(p), Some(s)) = (&keys.public_key, &keys.secret_key) {
         let keys_str = format!(r#"{{
             "public": "{}",
             "secret": "{}"
@@ -191,30 +449,61 @@ fn load_keypair(public_key: Option<&str>, secret_key: Option<&str>) -> Result<Op
         return Ok(serde_json::from_str(&keys_str)
             .map_err(|e| format!("failed to load keypair: {}", e))?);
     }
+
     Ok(None)
 }
 
+// Read-only counterpart to `resolve_keypair` for address computation: a
+// public key on its own is enough, so this skips the secret-key checks that
+// would otherwise force a caller who only has `public_key` to supply a
+// `secret_key` they don't need and may not have.
+pub(crate) async fn resolve_public_key(keys: &KeySource) -> Result<Option<String>, String> {
+    if let Some(phrase) = &keys.seed_phrase {
+        let ton = get_context()?;
+        return Ok(Some(derive_keypair_from_seed(ton, phrase, keys.hd_path.as_deref()).await?.public));
+    }
+    Ok(keys.public_key.clone())
+}
+
+async fn derive_keypair_from_seed(
+    ton: Arc<ClientContext>,
+    seed_phrase: &str,
+    hd_path: Option<&str>,
+) -> Result<KeyPair, String> {
+    let verified = ton_client::crypto::mnemonic_verify(
+        ton.clone(),
+        ton_client::crypto::ParamsOfMnemonicVerify {
+            phrase: seed_phrase.to_string(),
+            ..Default::default()
+        },
+    ).await.map_err(|e| format!("failed to verify seed phrase: {}", e))?;
+    if !verified.valid {
+        return Err("seed phrase is not a valid BIP39 mnemonic".to_string());
+    }
+
+    ton_client::crypto::mnemonic_derive_sign_keys(
+        ton,
+        ton_client::crypto::ParamsOfMnemonicDeriveSignKeys {
+            phrase: seed_phrase.to_string(),
+            path: Some(hd_path.unwrap_or(DEFAULT_HD_PATH).to_string()),
+            dictionary: None,
+            word_count: None,
+        },
+    ).await.map_err(|e| format!("failed to derive keys from seed phrase: {}", e))
+}
+
 async fn prepare_deploy_message(
     code_base64: &str,
-    abi_path: &str,
+    abi: Abi,
     params: &str,
-    public_key: Option<&str>,
-    secret_key: Option<&str>,
+    keypair: &Option<KeyPair>,
+    workchain_id: i32,
 ) -> Result<(ParamsOfEncodeMessage, String), String> {
-    let abi_str = std::fs::read_to_string(abi_path)
-        .map_err(|e| format!("failed to read ABI file: {}", e))?;
-    let abi = Abi::Contract(
-        serde_json::from_str::<AbiContract>(&abi_str)
-            .map_err(|e| format!("ABI is not a valid json: {}", e))?,
-    );
-    let keypair = load_keypair(
-        public_key,
-        secret_key,
-    )?;
     let addr = calc_acc_address(
         code_base64.to_string(),
         keypair.as_ref().map(|k| k.public.clone()),
-        abi.clone()
+        abi.clone(),
+        workchain_id,
     ).await?;
     let params = serde_json::from_str(params)
         .map_err(|e| format!("function arguments is not a json: {}", e))?;
@@ -224,11 +513,134 @@ async fn prepare_deploy_message(
         address: Some(addr.clone()),
         deploy_set: Some(DeploySet {
             tvc: code_base64.to_string(),
-            workchain_id: Some(WORKCHAIN),
+            workchain_id: Some(workchain_id),
             ..Default::default()
         }),
         call_set: CallSet::some_with_function_and_input("constructor", params),
-        signer: Signer::Keys{ keys: keypair.unwrap() },
+        signer: keypair_signer(keypair),
         ..Default::default()
     }, addr))
 }
+
+fn keypair_signer(keypair: &Option<KeyPair>) -> Signer {
+    match keypair {
+        Some(k) => Signer::Keys { keys: k.clone() },
+        None => Signer::None,
+    }
+}
+
+// Each entry names a contract function invoked against the freshly deployed
+// address once the deploy transaction succeeds, using the same ABI/signer.
+#[derive(Deserialize, Clone)]
+pub(crate) struct CallSpec {
+    pub function: String,
+    #[serde(default = "default_call_input")]
+    pub input: Value,
+}
+
+// An omitted `input` means a zero-argument call; the ABI encoder expects an
+// (empty) object for call args, not `Value`'s default `null`.
+fn default_call_input() -> Value {
+    json!({})
+}
+
+async fn run_post_deploy_calls(
+    ton: Arc<ClientContext>,
+    abi: &Abi,
+    addr: &str,
+    keypair: &Option<KeyPair>,
+    calls: &[CallSpec],
+) -> Result<std::collections::HashMap<String, Value>, String> {
+    let mut outputs = std::collections::HashMap::new();
+    for call in calls {
+        let msg = ParamsOfEncodeMessage {
+            abi: abi.clone(),
+            address: Some(addr.to_string()),
+            call_set: CallSet::some_with_function_and_input(&call.function, call.input.clone()),
+            signer: keypair_signer(keypair),
+            ..Default::default()
+        };
+        let output = process_message(ton.clone(), msg, print_processing_event).await?;
+        outputs.insert(call.function.clone(), output);
+    }
+    Ok(outputs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn resolve_keypair_rejects_seed_and_raw_keys_together() {
+        let keys = KeySource {
+            public_key: Some("pub".to_string()),
+            secret_key: Some("sec".to_string()),
+            seed_phrase: Some("abandon abandon abandon".to_string()),
+            hd_path: None,
+        };
+        let err = resolve_keypair(&keys).await.unwrap_err();
+        assert!(err.contains("not both"), "unexpected error: {}", err);
+    }
+
+    #[tokio::test]
+    async fn resolve_keypair_rejects_partial_raw_keys() {
+        let keys = KeySource {
+            public_key: Some("pub".to_string()),
+            secret_key: None,
+            seed_phrase: None,
+            hd_path: None,
+        };
+        let err = resolve_keypair(&keys).await.unwrap_err();
+        assert!(err.contains("must be supplied together"), "unexpected error: {}", err);
+    }
+
+    #[tokio::test]
+    async fn resolve_keypair_is_none_when_nothing_supplied() {
+        let keypair = resolve_keypair(&KeySource::default()).await.unwrap();
+        assert!(keypair.is_none());
+    }
+
+    #[tokio::test]
+    async fn resolve_public_key_accepts_public_key_alone() {
+        let keys = KeySource {
+            public_key: Some("pub".to_string()),
+            secret_key: None,
+            seed_phrase: None,
+            hd_path: None,
+        };
+        assert_eq!(resolve_public_key(&keys).await.unwrap(), Some("pub".to_string()));
+    }
+
+    #[tokio::test]
+    async fn resolve_public_key_is_none_when_nothing_supplied() {
+        assert_eq!(resolve_public_key(&KeySource::default()).await.unwrap(), None);
+    }
+
+    #[test]
+    fn preset_endpoints_rejects_unknown_preset() {
+        let err = preset_endpoints("testnet").unwrap_err();
+        assert!(err.contains("unknown network preset"), "unexpected error: {}", err);
+    }
+
+    #[test]
+    fn preset_endpoints_resolves_known_presets() {
+        assert_eq!(preset_endpoints("local").unwrap(), vec!["http://localhost".to_string()]);
+        assert!(preset_endpoints("mainnet").unwrap().contains(&"main.ton.dev".to_string()));
+    }
+
+    #[test]
+    fn resolve_endpoints_prefers_explicit_endpoints_over_preset() {
+        let network = NetworkSpec {
+            endpoints: Some(vec!["custom.example.com".to_string()]),
+            preset: Some("mainnet".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(resolve_endpoints(&network).unwrap(), vec!["custom.example.com".to_string()]);
+    }
+
+    #[test]
+    fn resolve_endpoints_falls_back_to_default_url() {
+        let network = NetworkSpec::default();
+        assert_eq!(resolve_endpoints(&network).unwrap(), vec![NETWORK_URL.to_string()]);
+    }
+}
@@ -0,0 +1,271 @@
+use std::collections::HashMap;
+use std::collections::VecDeque;
+
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::calc_acc_address;
+use crate::deploy_contract;
+use crate::parse_abi;
+use crate::print_processing_event;
+use crate::resolve_keypair;
+use crate::CallSpec;
+use crate::DeployOptions;
+use crate::GiverSpec;
+use crate::KeySource;
+use crate::NetworkSpec;
+use crate::WORKCHAIN;
+
+// A project manifest describing several interdependent contracts, deployed
+// in dependency order with computed addresses injected into later params.
+#[derive(Deserialize)]
+pub struct Manifest {
+    pub contracts: Vec<ManifestContract>,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct ManifestContract {
+    pub name: String,
+    pub abi_path: String,
+    pub code_base64: String,
+    #[serde(default)]
+    pub params: Value,
+    #[serde(default)]
+    pub depends_on: Vec<String>,
+    #[serde(default)]
+    pub calls: Vec<CallSpec>,
+    #[serde(default)]
+    pub giver: Option<GiverSpec>,
+    #[serde(default)]
+    pub wait_secs: Option<u64>,
+    #[serde(flatten)]
+    pub keys: KeySource,
+}
+
+fn load_manifest(path: &str) -> Result<Manifest, String> {
+    let text = std::fs::read_to_string(path)
+        .map_err(|e| format!("failed to read manifest: {}", e))?;
+    serde_json::from_str(&text).map_err(|e| format!("manifest is not a valid json: {}", e))
+}
+
+fn read_abi(path: &str) -> Result<ton_client::abi::Abi, String> {
+    let abi_str = std::fs::read_to_string(path)
+        .map_err(|e| format!("failed to read ABI file: {}", e))?;
+    parse_abi(&abi_str)
+}
+
+// Catches typo'd `depends_on` entries up front so they don't masquerade as a
+// dependency cycle once Kahn's algorithm gets ahold of them.
+fn validate_dependencies(contracts: &[ManifestContract]) -> Result<(), String> {
+    let mut names: std::collections::HashSet<&str> = std::collections::HashSet::new();
+    for c in contracts {
+        if !names.insert(c.name.as_str()) {
+            return Err(format!("duplicate contract name '{}' in manifest", c.name));
+        }
+    }
+    for c in contracts {
+        for dep in &c.depends_on {
+            if !names.contains(dep.as_str()) {
+                return Err(format!("contract '{}' depends on unknown contract '{}'", c.name, dep));
+            }
+        }
+    }
+    Ok(())
+}
+
+// Kahn's algorithm: repeatedly emit nodes with in-degree zero, decrementing
+// their dependents, until every contract is ordered or a cycle remains.
+fn topological_order(contracts: &[ManifestContract]) -> Result<Vec<String>, String> {
+    validate_dependencies(contracts)?;
+
+    let mut in_degree: HashMap<String, usize> = contracts.iter()
+        .map(|c| (c.name.clone(), c.depends_on.len()))
+        .collect();
+    let mut dependents: HashMap<String, Vec<String>> = HashMap::new();
+    for c in contracts {
+        for dep in &c.depends_on {
+            dependents.entry(dep.clone()).or_default().push(c.name.clone());
+        }
+    }
+
+    let mut queue: VecDeque<String> = in_degree.iter()
+        .filter(|(_, degree)| **degree == 0)
+        .map(|(name, _)| name.clone())
+        .collect();
+
+    let mut order = Vec::with_capacity(contracts.len());
+    while let Some(name) = queue.pop_front() {
+        order.push(name.clone());
+        if let Some(deps) = dependents.get(&name) {
+            for dependent in deps {
+                let degree = in_degree.get_mut(dependent).unwrap();
+                *degree -= 1;
+                if *degree == 0 {
+                    queue.push_back(dependent.clone());
+                }
+            }
+        }
+    }
+
+    if order.len() != contracts.len() {
+        let remaining: Vec<String> = in_degree.into_iter()
+            .filter(|(_, degree)| *degree > 0)
+            .map(|(name, _)| name)
+            .collect();
+        return Err(format!("dependency cycle detected among: {}", remaining.join(", ")));
+    }
+    Ok(order)
+}
+
+fn substitute_addresses(value: &Value, addresses: &HashMap<String, String>) -> Value {
+    match value {
+        Value::String(s) => match resolve_address_placeholder(s, addresses) {
+            Some(addr) => Value::String(addr),
+            None => Value::String(s.clone()),
+        },
+        Value::Array(items) => Value::Array(
+            items.iter().map(|v| substitute_addresses(v, addresses)).collect(),
+        ),
+        Value::Object(map) => Value::Object(
+            map.iter().map(|(k, v)| (k.clone(), substitute_addresses(v, addresses))).collect(),
+        ),
+        other => other.clone(),
+    }
+}
+
+fn resolve_address_placeholder(s: &str, addresses: &HashMap<String, String>) -> Option<String> {
+    let name = s.strip_prefix("${addr:")?.strip_suffix('}')?;
+    addresses.get(name).cloned()
+}
+
+pub async fn deploy_manifest(path: &str, network: NetworkSpec) -> Result<(), String> {
+    let manifest = load_manifest(path)?;
+    let order = topological_order(&manifest.contracts)?;
+    let by_name: HashMap<String, &ManifestContract> = manifest.contracts.iter()
+        .map(|c| (c.name.clone(), c))
+        .collect();
+    let workchain_id = network.workchain_id.unwrap_or(WORKCHAIN);
+
+    let mut addresses: HashMap<String, String> = HashMap::new();
+    for name in &order {
+        let contract = by_name[name];
+        let abi = read_abi(&contract.abi_path)?;
+        let keypair = resolve_keypair(&contract.keys).await?;
+        let address = calc_acc_address(
+            contract.code_base64.clone(),
+            keypair.map(|k| k.public),
+            abi,
+            workchain_id,
+        ).await?;
+        addresses.insert(name.clone(), address);
+    }
+
+    let mut deployed: HashMap<String, String> = HashMap::new();
+    for name in &order {
+        let contract = by_name[name];
+        let abi = read_abi(&contract.abi_path)?;
+        let params = substitute_addresses(&contract.params, &addresses);
+        let outcome = deploy_contract(
+            &contract.code_base64,
+            abi,
+            &params.to_string(),
+            DeployOptions {
+                keys: contract.keys.clone(),
+                wait_secs: contract.wait_secs,
+                calls: contract.calls.clone(),
+                giver: contract.giver.clone(),
+                network: network.clone(),
+            },
+            print_processing_event,
+        ).await?;
+        println!("{} deployed at address: {}", name, outcome.address);
+        for (function, output) in &outcome.call_outputs {
+            println!("  {}.{} returned: {}", name, function, output);
+        }
+        if let Some(account) = &outcome.account {
+            println!("  {} is Active, balance: {}", name, account["balance"].as_str().unwrap_or("unknown"));
+        }
+        deployed.insert(name.clone(), outcome.address);
+    }
+
+    println!("Deployment summary:");
+    for name in &order {
+        println!("  {} -> {}", name, deployed[name]);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn contract(name: &str, depends_on: &[&str]) -> ManifestContract {
+        ManifestContract {
+            name: name.to_string(),
+            abi_path: String::new(),
+            code_base64: String::new(),
+            params: Value::Null,
+            depends_on: depends_on.iter().map(|s| s.to_string()).collect(),
+            calls: Vec::new(),
+            giver: None,
+            wait_secs: None,
+            keys: KeySource::default(),
+        }
+    }
+
+    #[test]
+    fn topological_order_respects_linear_chain() {
+        let contracts = vec![
+            contract("a", &[]),
+            contract("b", &["a"]),
+            contract("c", &["b"]),
+        ];
+        let order = topological_order(&contracts).unwrap();
+        assert_eq!(order, vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn topological_order_rejects_cycle() {
+        let contracts = vec![
+            contract("a", &["b"]),
+            contract("b", &["a"]),
+        ];
+        let err = topological_order(&contracts).unwrap_err();
+        assert!(err.contains("dependency cycle detected"), "unexpected error: {}", err);
+    }
+
+    #[test]
+    fn topological_order_rejects_unknown_dependency() {
+        let contracts = vec![contract("a", &["missing"])];
+        let err = topological_order(&contracts).unwrap_err();
+        assert!(err.contains("unknown contract 'missing'"), "unexpected error: {}", err);
+    }
+
+    #[test]
+    fn topological_order_rejects_duplicate_name() {
+        let contracts = vec![contract("x", &[]), contract("x", &[])];
+        let err = topological_order(&contracts).unwrap_err();
+        assert!(err.contains("duplicate contract name 'x'"), "unexpected error: {}", err);
+    }
+
+    #[test]
+    fn substitute_addresses_replaces_placeholder() {
+        let mut addresses = HashMap::new();
+        addresses.insert("a".to_string(), "0:abc".to_string());
+        let value = serde_json::json!({
+            "dest": "${addr:a}",
+            "note": "unrelated",
+        });
+        let result = substitute_addresses(&value, &addresses);
+        assert_eq!(result["dest"], "0:abc");
+        assert_eq!(result["note"], "unrelated");
+    }
+
+    #[test]
+    fn substitute_addresses_leaves_unknown_placeholder_untouched() {
+        let addresses = HashMap::new();
+        let value = Value::String("${addr:unknown}".to_string());
+        let result = substitute_addresses(&value, &addresses);
+        assert_eq!(result, Value::String("${addr:unknown}".to_string()));
+    }
+}
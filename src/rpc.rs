@@ -0,0 +1,198 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+use serde::Deserialize;
+use serde_json::json;
+use serde_json::Value;
+use tokio::io::AsyncBufReadExt;
+use tokio::io::AsyncWriteExt;
+use tokio::io::BufReader;
+use tokio::net::TcpListener;
+use tokio::net::TcpStream;
+use tokio::sync::mpsc;
+use ton_client::processing::ProcessingEvent;
+
+use crate::calc_acc_address;
+use crate::deploy_contract;
+use crate::parse_abi;
+use crate::resolve_public_key;
+use crate::CallSpec;
+use crate::DeployOptions;
+use crate::GiverSpec;
+use crate::KeySource;
+use crate::NetworkSpec;
+use crate::WORKCHAIN;
+
+const DEFAULT_RPC_ADDR: &str = "127.0.0.1:8090";
+
+// Newline-delimited JSON-RPC 2.0: one request/response/notification per line.
+#[derive(Deserialize)]
+struct RpcRequest {
+    #[serde(default)]
+    #[allow(dead_code)]
+    jsonrpc: String,
+    method: String,
+    #[serde(default)]
+    params: Value,
+    #[serde(default)]
+    id: Option<Value>,
+}
+
+type SubscriberId = u64;
+
+pub async fn serve() -> Result<(), String> {
+    let addr = std::env::var("RPC_ADDR").unwrap_or_else(|_| DEFAULT_RPC_ADDR.to_string());
+    let listener = TcpListener::bind(&addr).await
+        .map_err(|e| format!("failed to bind {}: {}", addr, e))?;
+    println!("JSON-RPC server listening on {}", addr);
+
+    loop {
+        let (socket, _) = listener.accept().await
+            .map_err(|e| format!("accept failed: {}", e))?;
+        tokio::spawn(async move {
+            if let Err(e) = handle_connection(socket).await {
+                println!("RPC connection error: {}", e);
+            }
+        });
+    }
+}
+
+async fn handle_connection(socket: TcpStream) -> Result<(), String> {
+    let (read_half, mut write_half) = socket.into_split();
+    let mut lines = BufReader::new(read_half).lines();
+    let next_sub_id = Arc::new(AtomicU64::new(1));
+    let (out_tx, mut out_rx) = mpsc::unbounded_channel::<Value>();
+
+    let writer = tokio::spawn(async move {
+        while let Some(frame) = out_rx.recv().await {
+            if write_half.write_all(format!("{}\n", frame).as_bytes()).await.is_err() {
+                break;
+            }
+        }
+    });
+
+    while let Some(line) = lines.next_line().await.map_err(|e| e.to_string())? {
+        if line.trim().is_empty() {
+            continue;
+        }
+        match serde_json::from_str::<RpcRequest>(&line) {
+            Ok(req) => dispatch(req, next_sub_id.clone(), out_tx.clone()).await,
+            Err(e) => { let _ = out_tx.send(error_response(None, -32700, &format!("parse error: {}", e))); }
+        }
+    }
+
+    drop(out_tx);
+    let _ = writer.await;
+    Ok(())
+}
+
+async fn dispatch(
+    req: RpcRequest,
+    next_sub_id: Arc<AtomicU64>,
+    out: mpsc::UnboundedSender<Value>,
+) {
+    let id = req.id.clone();
+    match req.method.as_str() {
+        "calc_address" => {
+            let resp = match calc_address_params(&req.params).await {
+                Ok(address) => success_response(id, json!({ "address": address })),
+                Err(e) => error_response(id, -32000, &e),
+            };
+            let _ = out.send(resp);
+        }
+        "deploy" => {
+            let params = req.params.clone();
+            let sink = out.clone();
+            tokio::spawn(async move {
+                let resp = match deploy_params(&params, |_| {}).await {
+                    Ok(result) => success_response(id, result),
+                    Err(e) => error_response(id, -32000, &e),
+                };
+                let _ = sink.send(resp);
+            });
+        }
+        "deploy.subscribe" => {
+            let sub_id = next_sub_id.fetch_add(1, Ordering::SeqCst);
+            let _ = out.send(success_response(id, json!(sub_id)));
+
+            let params = req.params.clone();
+            let sink = out.clone();
+            tokio::spawn(async move {
+                let event_sink = sink.clone();
+                let result = deploy_params(&params, move |event| {
+                    let _ = event_sink.send(deploy_event_frame(sub_id, event_to_json(&event)));
+                }).await;
+
+                let final_result = match result {
+                    Ok(mut deployed) => {
+                        deployed["status"] = json!("succeeded");
+                        deployed
+                    }
+                    Err(e) => json!({ "status": "failed", "error": e }),
+                };
+                let _ = sink.send(deploy_event_frame(sub_id, final_result));
+            });
+        }
+        other => {
+            let _ = out.send(error_response(id, -32601, &format!("method not found: {}", other)));
+        }
+    }
+}
+
+fn deploy_event_frame(subscription: SubscriberId, result: Value) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "deploy.event",
+        "params": { "subscription": subscription, "result": result },
+    })
+}
+
+fn event_to_json(event: &ProcessingEvent) -> Value {
+    serde_json::to_value(event).unwrap_or_else(|_| json!({}))
+}
+
+fn success_response(id: Option<Value>, result: Value) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "result": result })
+}
+
+fn error_response(id: Option<Value>, code: i64, message: &str) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "error": { "code": code, "message": message } })
+}
+
+async fn calc_address_params(params: &Value) -> Result<String, String> {
+    let code_base64 = params["code_base64"].as_str().ok_or("code_base64 is required")?.to_string();
+    let abi = parse_abi(&params["abi"].to_string())?;
+    let keys: KeySource = serde_json::from_value(params.clone()).unwrap_or_default();
+    let public_key = resolve_public_key(&keys).await?;
+    let network: NetworkSpec = serde_json::from_value(params["network"].clone()).unwrap_or_default();
+    let workchain_id = network.workchain_id.unwrap_or(WORKCHAIN);
+    calc_acc_address(code_base64, public_key, abi, workchain_id).await
+}
+
+async fn deploy_params(
+    params: &Value,
+    on_event: impl Fn(ProcessingEvent) + Send + Sync + 'static,
+) -> Result<Value, String> {
+    let code_base64 = params["code_base64"].as_str().ok_or("code_base64 is required")?.to_string();
+    let abi = parse_abi(&params["abi"].to_string())?;
+    let call_params = params["params"].to_string();
+    let keys: KeySource = serde_json::from_value(params.clone()).unwrap_or_default();
+    let wait_secs = params["wait_secs"].as_u64();
+    let calls: Vec<CallSpec> = serde_json::from_value(params["calls"].clone()).unwrap_or_default();
+    let giver: Option<GiverSpec> = serde_json::from_value(params["giver"].clone()).ok();
+    let network: NetworkSpec = serde_json::from_value(params["network"].clone()).unwrap_or_default();
+    let outcome = deploy_contract(
+        &code_base64,
+        abi,
+        &call_params,
+        DeployOptions {
+            keys,
+            wait_secs,
+            calls,
+            giver,
+            network,
+        },
+        on_event,
+    ).await?;
+    Ok(json!({ "address": outcome.address, "calls": outcome.call_outputs, "account": outcome.account }))
+}